@@ -74,6 +74,34 @@ impl BigInt {
         bigint.to_string()
     }
 
+    /// `thisBigIntValue ( value )`
+    ///
+    /// Extracts the `[[BigIntData]]` of `value`, where `value` is either a
+    /// BigInt primitive or a BigInt wrapper object, throwing a `TypeError`
+    /// for anything else. Used by every `BigInt.prototype` method so that
+    /// e.g. `BigInt.prototype.toString.call({})` errors instead of
+    /// panicking.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-thisbigintvalue
+    fn this_bigint_value(this: &Value, ctx: &mut Interpreter) -> Result<AstBigInt, Value> {
+        if this.is_bigint() {
+            return Ok(this
+                .to_bigint()
+                .expect("this.is_bigint() was true"));
+        }
+
+        if let Some(data) = this.get_internal_slot("BigIntData") {
+            if let Some(bigint) = data.to_bigint() {
+                return Ok(bigint);
+            }
+        }
+
+        Err(ctx.throw_type_error("'this' is not a BigInt")?)
+    }
+
     /// `BigInt.prototype.toString( [radix] )`
     ///
     /// The `toString()` method returns a string representing the specified BigInt object.
@@ -90,21 +118,42 @@ impl BigInt {
         args: &[Value],
         ctx: &mut Interpreter,
     ) -> ResultValue {
+        let bigint = Self::this_bigint_value(this, ctx)?;
         let radix = if !args.is_empty() {
             args[0].to_integer()
         } else {
             10
         };
-        if radix < 2 && radix > 36 {
+        if radix < 2 || radix > 36 {
             return ctx
                 .throw_range_error("radix must be an integer at least 2 and no greater than 36");
         }
         Ok(Value::from(Self::to_native_string_radix(
-            &this.to_bigint().unwrap(),
+            &bigint,
             radix as u32,
         )))
     }
 
+    /// `BigInt.prototype.toLocaleString()`
+    ///
+    /// Without `Intl`, this falls back to the spec's default behaviour of
+    /// `toString()` with radix 10.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-bigint.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt/toLocaleString
+    pub(crate) fn to_locale_string(
+        this: &mut Value,
+        _args: &[Value],
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let bigint = Self::this_bigint_value(this, ctx)?;
+        Ok(Value::from(Self::to_native_string_radix(&bigint, 10)))
+    }
+
     /// `BigInt.prototype.valueOf()`
     ///
     /// The `valueOf()` method returns the wrapped primitive value of a Number object.
@@ -118,11 +167,9 @@ impl BigInt {
     pub(crate) fn value_of(
         this: &mut Value,
         _args: &[Value],
-        _ctx: &mut Interpreter,
+        ctx: &mut Interpreter,
     ) -> ResultValue {
-        Ok(Value::from(
-            this.to_bigint().expect("BigInt.prototype.valueOf"),
-        ))
+        Ok(Value::from(Self::this_bigint_value(this, ctx)?))
     }
 
     // /// `BigInt.asIntN()`
@@ -137,6 +184,10 @@ impl BigInt {
     ) -> ResultValue {
         let (modulo, bits) = Self::as_bigint_helper(args, ctx)?;
 
+        if bits == 0 {
+            return Ok(Value::from(AstBigInt::zero()));
+        }
+
         if modulo >= AstBigInt::from(2).pow(&AstBigInt::from(bits as i64 - 1)) {
             Ok(Value::from(
                 modulo - AstBigInt::from(2).pow(&AstBigInt::from(bits as i64)),
@@ -161,6 +212,158 @@ impl BigInt {
         Ok(Value::from(modulo))
     }
 
+    /// Returns the two operands of a binary numeric operator (`+`, `-`, `*`,
+    /// `/`, `%`, `**`, `&`, `|`, `^`, `<<`, `>>`) as BigInts, throwing a
+    /// `TypeError` unless both are BigInts.
+    ///
+    /// Per spec, mixing a `Number` and a `BigInt` operand is always a
+    /// `TypeError` — there is no implicit coercion between the two numeric
+    /// types.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-numeric-types-bigint-add
+    fn numeric_operands(
+        op: &str,
+        lhs: &Value,
+        rhs: &Value,
+        ctx: &mut Interpreter,
+    ) -> Result<(AstBigInt, AstBigInt), Value> {
+        match (lhs.is_bigint(), rhs.is_bigint()) {
+            (true, true) => Ok((
+                lhs.to_bigint().expect("lhs.is_bigint() was true"),
+                rhs.to_bigint().expect("rhs.is_bigint() was true"),
+            )),
+            _ => Err(ctx.throw_type_error(format!(
+                "cannot mix BigInt and other types, use explicit conversions for `{}`",
+                op
+            ))?),
+        }
+    }
+
+    /// `BigInt::add ( x, y )`
+    pub(crate) fn add(lhs: &Value, rhs: &Value, ctx: &mut Interpreter) -> ResultValue {
+        let (x, y) = Self::numeric_operands("+", lhs, rhs, ctx)?;
+        Ok(Value::from(x + y))
+    }
+
+    /// `BigInt::subtract ( x, y )`
+    pub(crate) fn sub(lhs: &Value, rhs: &Value, ctx: &mut Interpreter) -> ResultValue {
+        let (x, y) = Self::numeric_operands("-", lhs, rhs, ctx)?;
+        Ok(Value::from(x - y))
+    }
+
+    /// `BigInt::multiply ( x, y )`
+    pub(crate) fn mul(lhs: &Value, rhs: &Value, ctx: &mut Interpreter) -> ResultValue {
+        let (x, y) = Self::numeric_operands("*", lhs, rhs, ctx)?;
+        Ok(Value::from(x * y))
+    }
+
+    /// `BigInt::divide ( x, y )`
+    ///
+    /// Division truncates toward zero, as `num_bigint`'s `Div` impl already
+    /// does for its underlying sign-and-magnitude representation.
+    pub(crate) fn div(lhs: &Value, rhs: &Value, ctx: &mut Interpreter) -> ResultValue {
+        let (x, y) = Self::numeric_operands("/", lhs, rhs, ctx)?;
+        if y == AstBigInt::zero() {
+            return ctx.throw_range_error("BigInt division by zero");
+        }
+        Ok(Value::from(x.div_trunc(&y)))
+    }
+
+    /// `BigInt::remainder ( x, y )`
+    ///
+    /// The remainder truncates toward zero to match `div`, so
+    /// `(x / y) * y + (x % y) == x` holds as it does for `Number`.
+    pub(crate) fn rem(lhs: &Value, rhs: &Value, ctx: &mut Interpreter) -> ResultValue {
+        let (x, y) = Self::numeric_operands("%", lhs, rhs, ctx)?;
+        if y == AstBigInt::zero() {
+            return ctx.throw_range_error("BigInt division by zero");
+        }
+        Ok(Value::from(x.rem_trunc(&y)))
+    }
+
+    /// `BigInt::exponentiate ( x, y )`
+    pub(crate) fn pow(lhs: &Value, rhs: &Value, ctx: &mut Interpreter) -> ResultValue {
+        let (x, y) = Self::numeric_operands("**", lhs, rhs, ctx)?;
+        if y.is_negative() {
+            return ctx.throw_range_error("BigInt negative exponent");
+        }
+        if !y.fits_in_u32() {
+            return ctx.throw_range_error("BigInt exponent is too large to be represented");
+        }
+        Ok(Value::from(x.pow(&y)))
+    }
+
+    /// `BigInt::bitwiseAND ( x, y )`
+    pub(crate) fn bitand(lhs: &Value, rhs: &Value, ctx: &mut Interpreter) -> ResultValue {
+        let (x, y) = Self::numeric_operands("&", lhs, rhs, ctx)?;
+        Ok(Value::from(x & y))
+    }
+
+    /// `BigInt::bitwiseOR ( x, y )`
+    pub(crate) fn bitor(lhs: &Value, rhs: &Value, ctx: &mut Interpreter) -> ResultValue {
+        let (x, y) = Self::numeric_operands("|", lhs, rhs, ctx)?;
+        Ok(Value::from(x | y))
+    }
+
+    /// `BigInt::bitwiseXOR ( x, y )`
+    pub(crate) fn bitxor(lhs: &Value, rhs: &Value, ctx: &mut Interpreter) -> ResultValue {
+        let (x, y) = Self::numeric_operands("^", lhs, rhs, ctx)?;
+        Ok(Value::from(x ^ y))
+    }
+
+    /// `~x`, implemented as the two's-complement identity `~x == -(x + 1)`.
+    pub(crate) fn not(value: &Value, ctx: &mut Interpreter) -> ResultValue {
+        match value.to_bigint() {
+            Some(x) => Ok(Value::from(!x)),
+            None => Err(ctx.throw_type_error("cannot apply `~` to a non-BigInt value")?),
+        }
+    }
+
+    /// `BigInt::leftShift ( x, y )`
+    ///
+    /// Defined as multiplication by `2**y`; a negative `y` is a right shift
+    /// by `-y` instead.
+    pub(crate) fn shift_left(lhs: &Value, rhs: &Value, ctx: &mut Interpreter) -> ResultValue {
+        let (x, y) = Self::numeric_operands("<<", lhs, rhs, ctx)?;
+        Self::shift(x, y, ctx)
+    }
+
+    /// `BigInt::signedRightShift ( x, y )`
+    ///
+    /// An arithmetic (sign-propagating) shift, defined as floor division by
+    /// `2**y`; a negative `y` is a left shift by `-y` instead.
+    pub(crate) fn shift_right(lhs: &Value, rhs: &Value, ctx: &mut Interpreter) -> ResultValue {
+        let (x, y) = Self::numeric_operands(">>", lhs, rhs, ctx)?;
+        Self::shift(x, -y, ctx)
+    }
+
+    /// `BigInt::unsignedRightShift` has no meaningful definition over an
+    /// unbounded-width integer, so `>>>` on a BigInt is always a
+    /// `TypeError`.
+    pub(crate) fn unsigned_shift_right(
+        _lhs: &Value,
+        _rhs: &Value,
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        ctx.throw_type_error("BigInts have no unsigned right shift, use >> instead")
+    }
+
+    /// Shared implementation for `<<` and `>>`: shifts `x` left by `shift`
+    /// bits, where a negative `shift` reverses the direction. A left shift of
+    /// a non-zero `x` by more than `u32::MAX` bits is a `RangeError`.
+    fn shift(x: AstBigInt, shift: AstBigInt, ctx: &mut Interpreter) -> ResultValue {
+        if shift.is_negative() {
+            Ok(Value::from(x >> shift.magnitude_u32()))
+        } else if !shift.fits_in_u32() && x != AstBigInt::zero() {
+            ctx.throw_range_error("Maximum BigInt size exceeded")
+        } else {
+            Ok(Value::from(x << shift.magnitude_u32()))
+        }
+    }
+
     fn as_bigint_helper(args: &[Value], ctx: &mut Interpreter) -> Result<(AstBigInt, u32), Value> {
         use std::convert::TryFrom;
 
@@ -202,6 +405,7 @@ impl BigInt {
         prototype.set_internal_slot("BigIntData", Value::from(AstBigInt::from(0)));
 
         make_builtin_fn(Self::to_string, "toString", &prototype, 1);
+        make_builtin_fn(Self::to_locale_string, "toLocaleString", &prototype, 0);
         make_builtin_fn(Self::value_of, "valueOf", &prototype, 0);
 
         let big_int = make_constructor_fn("BigInt", 1, Self::make_bigint, global, prototype, false);
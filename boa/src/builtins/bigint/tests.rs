@@ -0,0 +1,134 @@
+use crate::{exec::Interpreter, forward, realm::Realm};
+
+fn engine() -> Interpreter {
+    let realm = Realm::create();
+    Interpreter::new(realm)
+}
+
+#[test]
+fn add_sub_mul() {
+    let mut engine = engine();
+    assert_eq!(forward(&mut engine, "(10n + 20n).toString()"), "30");
+    assert_eq!(forward(&mut engine, "(10n - 20n).toString()"), "-10");
+    assert_eq!(forward(&mut engine, "(10n * 20n).toString()"), "200");
+}
+
+#[test]
+fn div_and_rem_truncate_toward_zero() {
+    let mut engine = engine();
+    assert_eq!(forward(&mut engine, "(7n / 2n).toString()"), "3");
+    assert_eq!(forward(&mut engine, "(-7n / 2n).toString()"), "-3");
+    assert_eq!(forward(&mut engine, "(7n % 2n).toString()"), "1");
+    assert_eq!(forward(&mut engine, "(-7n % 2n).toString()"), "-1");
+}
+
+#[test]
+fn division_by_zero_is_a_range_error() {
+    let mut engine = engine();
+    let result = forward(&mut engine, "try { 1n / 0n; 'no error' } catch (e) { e.name }");
+    assert_eq!(result, "RangeError");
+}
+
+#[test]
+fn exponentiation() {
+    let mut engine = engine();
+    assert_eq!(forward(&mut engine, "(2n ** 10n).toString()"), "1024");
+}
+
+#[test]
+fn negative_exponent_is_a_range_error() {
+    let mut engine = engine();
+    let result = forward(&mut engine, "try { 2n ** -1n; 'no error' } catch (e) { e.name }");
+    assert_eq!(result, "RangeError");
+}
+
+#[test]
+fn oversized_exponent_is_a_range_error_not_a_panic() {
+    let mut engine = engine();
+    let result = forward(
+        &mut engine,
+        "try { 2n ** 5000000000n; 'no error' } catch (e) { e.name }",
+    );
+    assert_eq!(result, "RangeError");
+}
+
+#[test]
+fn bitwise_and_not() {
+    let mut engine = engine();
+    assert_eq!(forward(&mut engine, "(5n & 3n).toString()"), "1");
+    assert_eq!(forward(&mut engine, "(5n | 2n).toString()"), "7");
+    assert_eq!(forward(&mut engine, "(5n ^ 1n).toString()"), "4");
+    assert_eq!(forward(&mut engine, "(~0n).toString()"), "-1");
+    assert_eq!(forward(&mut engine, "(~(-1n)).toString()"), "0");
+}
+
+#[test]
+fn shifts() {
+    let mut engine = engine();
+    assert_eq!(forward(&mut engine, "(1n << 10n).toString()"), "1024");
+    assert_eq!(forward(&mut engine, "(1024n >> 10n).toString()"), "1");
+    assert_eq!(forward(&mut engine, "(-1024n >> 10n).toString()"), "-1");
+}
+
+#[test]
+fn unsigned_right_shift_is_a_type_error() {
+    let mut engine = engine();
+    let result = forward(&mut engine, "try { 1n >>> 1n; 'no error' } catch (e) { e.name }");
+    assert_eq!(result, "TypeError");
+}
+
+#[test]
+fn mixing_bigint_and_number_is_a_type_error() {
+    let mut engine = engine();
+    let result = forward(&mut engine, "try { 1n + 1; 'no error' } catch (e) { e.name }");
+    assert_eq!(result, "TypeError");
+}
+
+#[test]
+fn as_int_n_with_zero_bits_is_zero_and_does_not_panic() {
+    let mut engine = engine();
+    assert_eq!(forward(&mut engine, "BigInt.asIntN(0, 1n).toString()"), "0");
+    assert_eq!(forward(&mut engine, "BigInt.asIntN(0, -5n).toString()"), "0");
+}
+
+#[test]
+fn to_string_on_non_bigint_this_is_a_type_error() {
+    let mut engine = engine();
+    let result = forward(
+        &mut engine,
+        "try { BigInt.prototype.toString.call({}); 'no error' } catch (e) { e.name }",
+    );
+    assert_eq!(result, "TypeError");
+}
+
+#[test]
+fn this_bigint_value_check_runs_before_radix_validation() {
+    let mut engine = engine();
+    let result = forward(
+        &mut engine,
+        "try { BigInt.prototype.toString.call({}, 999); 'no error' } catch (e) { e.name }",
+    );
+    assert_eq!(result, "TypeError");
+}
+
+#[test]
+fn to_string_with_radix() {
+    let mut engine = engine();
+    assert_eq!(forward(&mut engine, "255n.toString(16)"), "ff");
+}
+
+#[test]
+fn to_locale_string_falls_back_to_decimal_to_string() {
+    let mut engine = engine();
+    assert_eq!(forward(&mut engine, "12345n.toLocaleString()"), "12345");
+}
+
+#[test]
+fn to_locale_string_on_non_bigint_this_is_a_type_error() {
+    let mut engine = engine();
+    let result = forward(
+        &mut engine,
+        "try { BigInt.prototype.toLocaleString.call({}); 'no error' } catch (e) { e.name }",
+    );
+    assert_eq!(result, "TypeError");
+}
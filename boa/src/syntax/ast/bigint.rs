@@ -0,0 +1,268 @@
+//! This module implements the `BigInt` type, an arbitrary-precision integer
+//! used to back both `Number`-like BigInt primitives and the `BigInt`
+//! literal and constructor.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-ecmascript-language-types-bigint-type
+
+use num_bigint::BigInt as NumBigInt;
+use num_integer::Integer as NumInteger;
+use std::fmt;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub};
+
+/// An arbitrary-precision integer.
+///
+/// Wraps [`num_bigint::BigInt`] so the rest of the engine only ever has to
+/// deal with this newtype, not the backing crate's API directly.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BigInt(NumBigInt);
+
+impl BigInt {
+    /// Creates a `BigInt` with value `0`.
+    #[inline]
+    pub fn zero() -> Self {
+        Self(NumBigInt::from(0))
+    }
+
+    /// Parses a `BigInt` from the digits of an integer literal in the given
+    /// `radix` (2, 8, 10 or 16), without any `0x`/`0o`/`0b` prefix or `n`
+    /// suffix.
+    pub fn from_str_radix(digits: &str, radix: u32) -> Option<Self> {
+        NumBigInt::parse_bytes(digits.as_bytes(), radix).map(Self)
+    }
+
+    /// Returns the value formatted in the given `radix` (2 to 36 inclusive).
+    #[inline]
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        self.0.to_str_radix(radix)
+    }
+
+    /// Raises `self` to the power of `exponent`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `exponent` is negative or does not fit in a `u32`; callers
+    /// are expected to have already rejected negative exponents with a
+    /// `RangeError` and exponents wider than `u32::MAX` (see
+    /// [`Self::fits_in_u32`]) with their own `RangeError`.
+    pub fn pow(&self, exponent: &Self) -> Self {
+        let exponent = exponent
+            .0
+            .to_biguint()
+            .expect("BigInt::pow called with a negative exponent")
+            .to_u32_digits();
+        let exponent = match exponent.as_slice() {
+            [] => 0,
+            [only] => *only,
+            _ => panic!("BigInt::pow exponent out of range"),
+        };
+        Self(self.0.pow(exponent))
+    }
+
+    /// `true` if `self` is non-negative and small enough to pass to
+    /// [`Self::pow`] or [`Self::magnitude_u32`] without panicking/saturating
+    /// information away.
+    #[inline]
+    pub fn fits_in_u32(&self) -> bool {
+        !self.is_negative() && self.0.magnitude().to_u32_digits().len() <= 1
+    }
+
+    /// Euclidean-style modulo that always returns a non-negative result for
+    /// a positive modulus, mirroring [`num_integer::Integer::mod_floor`].
+    #[inline]
+    pub fn mod_floor(&self, other: &Self) -> Self {
+        Self(self.0.mod_floor(&other.0))
+    }
+
+    /// Truncating division, as used by the `/` operator on BigInts.
+    #[inline]
+    pub fn div_trunc(&self, other: &Self) -> Self {
+        Self(&self.0 / &other.0)
+    }
+
+    /// Truncating remainder, as used by the `%` operator on BigInts.
+    #[inline]
+    pub fn rem_trunc(&self, other: &Self) -> Self {
+        Self(&self.0 % &other.0)
+    }
+
+    /// `true` if the value is negative.
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.0.sign() == num_bigint::Sign::Minus
+    }
+
+    /// Returns `|self|`, saturated to `u32::MAX` if it doesn't fit.
+    ///
+    /// Used for shift amounts: a right shift wider than `u32::MAX` bits
+    /// produces the same observable result as one of exactly `u32::MAX` bits
+    /// (every bit ends up zero or sign-extended), so saturating is safe
+    /// there. A saturated left shift gives a different value for any
+    /// non-zero operand.
+    pub fn magnitude_u32(&self) -> u32 {
+        let digits = self.0.magnitude().to_u32_digits();
+        match digits.as_slice() {
+            [] => 0,
+            [only] => *only,
+            _ => u32::MAX,
+        }
+    }
+}
+
+impl From<i64> for BigInt {
+    #[inline]
+    fn from(value: i64) -> Self {
+        Self(NumBigInt::from(value))
+    }
+}
+
+impl From<i32> for BigInt {
+    #[inline]
+    fn from(value: i32) -> Self {
+        Self(NumBigInt::from(value))
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+macro_rules! impl_bin_op {
+    ($trait:ident, $method:ident) => {
+        impl $trait for BigInt {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self {
+                Self($trait::$method(self.0, rhs.0))
+            }
+        }
+
+        impl $trait for &BigInt {
+            type Output = BigInt;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> BigInt {
+                BigInt($trait::$method(&self.0, &rhs.0))
+            }
+        }
+    };
+}
+
+impl_bin_op!(Add, add);
+impl_bin_op!(Sub, sub);
+impl_bin_op!(Mul, mul);
+impl_bin_op!(BitAnd, bitand);
+impl_bin_op!(BitOr, bitor);
+impl_bin_op!(BitXor, bitxor);
+
+impl Div for BigInt {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        Self(self.0 / rhs.0)
+    }
+}
+
+impl Rem for BigInt {
+    type Output = Self;
+
+    #[inline]
+    fn rem(self, rhs: Self) -> Self {
+        Self(self.0 % rhs.0)
+    }
+}
+
+impl Neg for BigInt {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Not for BigInt {
+    type Output = Self;
+
+    /// Two's-complement bitwise NOT: `~x == -(x + 1)`.
+    #[inline]
+    fn not(self) -> Self {
+        Self(-(self.0 + 1i32))
+    }
+}
+
+impl Shl<u32> for BigInt {
+    type Output = Self;
+
+    /// `x << n` is defined as multiplication by `2**n`.
+    #[inline]
+    fn shl(self, rhs: u32) -> Self {
+        Self(self.0 << rhs)
+    }
+}
+
+impl Shr<u32> for BigInt {
+    type Output = Self;
+
+    /// `x >> n` is an arithmetic (sign-propagating) shift, i.e. floor
+    /// division by `2**n`.
+    #[inline]
+    fn shr(self, rhs: u32) -> Self {
+        Self(self.0 >> rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BigInt;
+
+    #[test]
+    fn from_str_radix_decimal() {
+        let big = BigInt::from_str_radix("9007199254740993", 10).unwrap();
+        assert_eq!(big.to_str_radix(10), "9007199254740993");
+    }
+
+    #[test]
+    fn from_str_radix_hex_octal_binary() {
+        assert_eq!(BigInt::from_str_radix("ff", 16).unwrap().to_str_radix(10), "255");
+        assert_eq!(BigInt::from_str_radix("17", 8).unwrap().to_str_radix(10), "15");
+        assert_eq!(BigInt::from_str_radix("101", 2).unwrap().to_str_radix(10), "5");
+    }
+
+    #[test]
+    fn display_matches_to_str_radix_10() {
+        let big = BigInt::from(12345i64);
+        assert_eq!(big.to_string(), big.to_str_radix(10));
+    }
+
+    #[test]
+    fn not_is_negate_of_increment() {
+        // ~x == -(x + 1)
+        assert_eq!(!BigInt::from(0i32), BigInt::from(-1i32));
+        assert_eq!(!BigInt::from(5i32), BigInt::from(-6i32));
+        assert_eq!(!BigInt::from(-6i32), BigInt::from(5i32));
+    }
+
+    #[test]
+    fn shift_left_and_right_match_power_of_two() {
+        assert_eq!(BigInt::from(1i32) << 10u32, BigInt::from(1024i32));
+        assert_eq!(BigInt::from(1024i32) >> 10u32, BigInt::from(1i32));
+        assert_eq!(BigInt::from(-1024i32) >> 10u32, BigInt::from(-1i32));
+    }
+
+    #[test]
+    fn fits_in_u32_rejects_negative_and_oversized() {
+        assert!(BigInt::from(0i32).fits_in_u32());
+        assert!(BigInt::from(u32::MAX as i64).fits_in_u32());
+        assert!(!BigInt::from(-1i32).fits_in_u32());
+        assert!(!BigInt::from_str_radix("5000000000", 10)
+            .unwrap()
+            .fits_in_u32());
+    }
+}
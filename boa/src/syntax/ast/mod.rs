@@ -0,0 +1,8 @@
+//! This module implements the abstract syntax tree and its constituent
+//! parts: literals, operators, punctuators and tokens.
+
+pub mod bigint;
+pub mod node;
+pub mod op;
+pub mod punc;
+pub mod token;
@@ -0,0 +1,54 @@
+//! This module implements the `Node` type, the building block of the AST
+//! produced by the parser and consumed by the interpreter.
+
+use super::bigint::BigInt as AstBigInt;
+use super::op::UnaryOp;
+
+/// A node of the abstract syntax tree.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    /// A numeric literal, e.g. `1.5`.
+    NumericLiteral(f64),
+    /// A `BigInt` literal, e.g. `9007199254740993n`.
+    BigIntLiteral(AstBigInt),
+    /// A string literal.
+    StringLiteral(String),
+    /// A boolean literal.
+    BooleanLiteral(bool),
+    /// The `null` literal.
+    NullLiteral,
+    /// An identifier reference, e.g. `foo`.
+    Identifier(String),
+    /// A member access with a static name, e.g. `foo.bar`.
+    GetConstField(Box<Node>, String),
+    /// A member access with a computed name, e.g. `foo[bar]`.
+    GetField(Box<Node>, Box<Node>),
+    /// A function or method call, e.g. `foo()`.
+    Call(Box<Node>, Vec<Node>),
+    /// A unary operator applied to an operand, e.g. `++foo`, `typeof foo`.
+    UnaryOp(UnaryOp, Box<Node>),
+}
+
+impl Node {
+    /// Creates a unary operator node.
+    #[inline]
+    pub fn unary_op(op: UnaryOp, target: Node) -> Self {
+        Self::UnaryOp(op, Box::new(target))
+    }
+
+    /// Returns `true` if this node is a valid simple assignment target
+    /// (an `IdentifierReference`, or a member/element access), per the
+    /// spec's notion of a node with a `Reference` assignment target type.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-assignmenttargettype
+    #[inline]
+    pub fn is_simple_assignment_target(&self) -> bool {
+        matches!(
+            self,
+            Self::Identifier(_) | Self::GetConstField(_, _) | Self::GetField(_, _)
+        )
+    }
+}
@@ -0,0 +1,102 @@
+//! This module implements the operators used by unary and binary expression
+//! nodes.
+
+use std::fmt;
+
+/// A unary operator, applied to a single operand.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-unary-operators
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnaryOp {
+    IncrementPre,
+    IncrementPost,
+    DecrementPre,
+    DecrementPost,
+    Plus,
+    Minus,
+    Not,
+    Tilde,
+    Void,
+    Delete,
+    TypeOf,
+}
+
+impl fmt::Display for UnaryOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::IncrementPre | Self::IncrementPost => "++",
+            Self::DecrementPre | Self::DecrementPost => "--",
+            Self::Plus => "+",
+            Self::Minus => "-",
+            Self::Not => "!",
+            Self::Tilde => "~",
+            Self::Void => "void",
+            Self::Delete => "delete",
+            Self::TypeOf => "typeof",
+        })
+    }
+}
+
+/// A binary operator, applied to two operands.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-binary-logical-operators
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+    Exp,
+    And,
+    Or,
+    Xor,
+    Shl,
+    Shr,
+    UShr,
+    Eq,
+    NotEq,
+    StrictEq,
+    StrictNotEq,
+    LessThan,
+    GreaterThan,
+    LessThanOrEq,
+    GreaterThanOrEq,
+    BoolAnd,
+    BoolOr,
+}
+
+impl fmt::Display for BinOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Add => "+",
+            Self::Sub => "-",
+            Self::Mul => "*",
+            Self::Div => "/",
+            Self::Mod => "%",
+            Self::Exp => "**",
+            Self::And => "&",
+            Self::Or => "|",
+            Self::Xor => "^",
+            Self::Shl => "<<",
+            Self::Shr => ">>",
+            Self::UShr => ">>>",
+            Self::Eq => "==",
+            Self::NotEq => "!=",
+            Self::StrictEq => "===",
+            Self::StrictNotEq => "!==",
+            Self::LessThan => "<",
+            Self::GreaterThan => ">",
+            Self::LessThanOrEq => "<=",
+            Self::GreaterThanOrEq => ">=",
+            Self::BoolAnd => "&&",
+            Self::BoolOr => "||",
+        })
+    }
+}
@@ -0,0 +1,101 @@
+//! This module implements the `Punctuator`, which represents all of the
+//! punctuation symbols the lexer and parser need to recognise.
+
+use std::fmt;
+
+/// A single punctuator symbol.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-punctuators
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Punctuator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+    Exp,
+    Inc,
+    Dec,
+    Assign,
+    Eq,
+    StrictEq,
+    NotEq,
+    StrictNotEq,
+    LessThan,
+    GreaterThan,
+    LessThanOrEq,
+    GreaterThanOrEq,
+    And,
+    Or,
+    Xor,
+    Not,
+    Neg,
+    BoolAnd,
+    BoolOr,
+    Shl,
+    Shr,
+    UShr,
+    OpenParen,
+    CloseParen,
+    OpenBlock,
+    CloseBlock,
+    OpenBracket,
+    CloseBracket,
+    Comma,
+    Semicolon,
+    Colon,
+    Dot,
+    Question,
+    Arrow,
+    Spread,
+}
+
+impl fmt::Display for Punctuator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Add => "+",
+            Self::Sub => "-",
+            Self::Mul => "*",
+            Self::Div => "/",
+            Self::Mod => "%",
+            Self::Exp => "**",
+            Self::Inc => "++",
+            Self::Dec => "--",
+            Self::Assign => "=",
+            Self::Eq => "==",
+            Self::StrictEq => "===",
+            Self::NotEq => "!=",
+            Self::StrictNotEq => "!==",
+            Self::LessThan => "<",
+            Self::GreaterThan => ">",
+            Self::LessThanOrEq => "<=",
+            Self::GreaterThanOrEq => ">=",
+            Self::And => "&",
+            Self::Or => "|",
+            Self::Xor => "^",
+            Self::Not => "!",
+            Self::Neg => "~",
+            Self::BoolAnd => "&&",
+            Self::BoolOr => "||",
+            Self::Shl => "<<",
+            Self::Shr => ">>",
+            Self::UShr => ">>>",
+            Self::OpenParen => "(",
+            Self::CloseParen => ")",
+            Self::OpenBlock => "{",
+            Self::CloseBlock => "}",
+            Self::OpenBracket => "[",
+            Self::CloseBracket => "]",
+            Self::Comma => ",",
+            Self::Semicolon => ";",
+            Self::Colon => ":",
+            Self::Dot => ".",
+            Self::Question => "?",
+            Self::Arrow => "=>",
+            Self::Spread => "...",
+        })
+    }
+}
@@ -0,0 +1,53 @@
+//! This module implements the `Token` and `TokenKind` types produced by the
+//! lexer and consumed by the parser.
+
+use super::bigint::BigInt as AstBigInt;
+use super::punc::Punctuator;
+use std::fmt;
+
+/// The different kinds of tokens the lexer can produce.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    Punctuator(Punctuator),
+    Identifier(String),
+    Keyword(String),
+    BooleanLiteral(bool),
+    NumericLiteral(f64),
+    /// An arbitrary-precision integer literal, e.g. `9007199254740993n`.
+    BigIntLiteral(AstBigInt),
+    StringLiteral(String),
+    NullLiteral,
+    LineTerminator,
+    EOF,
+}
+
+impl fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Punctuator(p) => write!(f, "{}", p),
+            Self::Identifier(i) => write!(f, "{}", i),
+            Self::Keyword(k) => write!(f, "{}", k),
+            Self::BooleanLiteral(b) => write!(f, "{}", b),
+            Self::NumericLiteral(n) => write!(f, "{}", n),
+            Self::BigIntLiteral(n) => write!(f, "{}n", n),
+            Self::StringLiteral(s) => write!(f, "{}", s),
+            Self::NullLiteral => write!(f, "null"),
+            Self::LineTerminator => write!(f, "\\n"),
+            Self::EOF => write!(f, "<EOF>"),
+        }
+    }
+}
+
+/// A single lexed token.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+}
+
+impl Token {
+    /// Creates a new token of the given kind.
+    #[inline]
+    pub fn new(kind: TokenKind) -> Self {
+        Self { kind }
+    }
+}
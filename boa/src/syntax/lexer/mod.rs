@@ -0,0 +1,31 @@
+//! This module implements the lexer's numeric and `BigInt` literal scanning
+//! ([`scan_number`]), along with the error and source-position types it
+//! reports.
+
+mod number;
+
+pub(crate) use number::scan as scan_number;
+
+use std::fmt;
+
+/// An error produced while lexing source text.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// A general syntax error, with a human-readable description.
+    Syntax(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+/// A single lexer position within the source, used to report errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Position {
+    pub line_number: u32,
+    pub column_number: u32,
+}
@@ -0,0 +1,172 @@
+//! Numeric and `BigInt` literal lexing.
+//!
+//! More information:
+//!  - [ECMAScript specification][spec-numeric]
+//!  - [ECMAScript specification][spec-bigint]
+//!
+//! [spec-numeric]: https://tc39.es/ecma262/#sec-literals-numeric-literals
+//! [spec-bigint]: https://tc39.es/ecma262/#sec-ecmascript-language-types-bigint-type
+
+use super::Error;
+use crate::syntax::ast::{bigint::BigInt as AstBigInt, token::TokenKind};
+
+/// Scans a numeric or `BigInt` literal starting at the beginning of
+/// `input`, returning its `TokenKind` and the number of bytes consumed.
+///
+/// `input` must start at the first character of the literal (a digit, or
+/// `.` followed by a digit).
+pub(crate) fn scan(input: &str) -> Result<(TokenKind, usize), Error> {
+    let bytes = input.as_bytes();
+
+    let (radix, digits_start) = match bytes {
+        [b'0', b'x' | b'X', ..] => (16, 2),
+        [b'0', b'o' | b'O', ..] => (8, 2),
+        [b'0', b'b' | b'B', ..] => (2, 2),
+        _ => (10, 0),
+    };
+
+    let mut pos = digits_start;
+    while bytes.get(pos).is_some_and(|b| (*b as char).is_digit(radix)) {
+        pos += 1;
+    }
+    let int_end = pos;
+
+    // A radix prefix always denotes an integer; only base-10 literals may
+    // have a fractional part or exponent.
+    let mut is_float = false;
+    if radix == 10 {
+        if bytes.get(pos) == Some(&b'.') {
+            is_float = true;
+            pos += 1;
+            while bytes.get(pos).is_some_and(u8::is_ascii_digit) {
+                pos += 1;
+            }
+        }
+        if matches!(bytes.get(pos), Some(b'e' | b'E')) {
+            is_float = true;
+            let mut exp_pos = pos + 1;
+            if matches!(bytes.get(exp_pos), Some(b'+' | b'-')) {
+                exp_pos += 1;
+            }
+            if bytes.get(exp_pos).is_some_and(u8::is_ascii_digit) {
+                pos = exp_pos;
+                while bytes.get(pos).is_some_and(u8::is_ascii_digit) {
+                    pos += 1;
+                }
+            }
+        }
+    }
+
+    if bytes.get(pos) == Some(&b'n') {
+        if is_float {
+            return Err(Error::Syntax(
+                "invalid BigInt literal: a BigInt literal must not have a fractional part or exponent".into(),
+            ));
+        }
+        // `BigIntLiteral` only permits a lone `0` or `NonZeroDigit
+        // DecimalDigits` before the suffix, so a decimal literal with a
+        // leading zero and more than one digit (e.g. `007n`) is an early
+        // SyntaxError, unlike a plain `NumericLiteral` which tolerates it.
+        if radix == 10 && int_end - digits_start > 1 && bytes[digits_start] == b'0' {
+            return Err(Error::Syntax(
+                "invalid BigInt literal: a decimal BigInt literal must not have a leading zero"
+                    .into(),
+            ));
+        }
+        let digits = &input[digits_start..int_end];
+        let value = AstBigInt::from_str_radix(digits, radix)
+            .ok_or_else(|| Error::Syntax("invalid BigInt literal".into()))?;
+        return Ok((TokenKind::BigIntLiteral(value), pos + 1));
+    }
+
+    let value = if radix == 10 {
+        input[..pos]
+            .parse::<f64>()
+            .map_err(|_| Error::Syntax("invalid numeric literal".into()))?
+    } else {
+        // Non-decimal literals have no upper bound on magnitude (e.g.
+        // `0xFFFFFFFF00000000`), so parse through an arbitrary-precision
+        // integer rather than `i64`/`u64`, then round to the nearest `f64`
+        // the same way the decimal branch's `f64::parse` already does.
+        AstBigInt::from_str_radix(&input[digits_start..pos], radix)
+            .ok_or_else(|| Error::Syntax("invalid numeric literal".into()))?
+            .to_str_radix(10)
+            .parse::<f64>()
+            .map_err(|_| Error::Syntax("invalid numeric literal".into()))?
+    };
+
+    Ok((TokenKind::NumericLiteral(value), pos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::scan;
+    use crate::syntax::ast::token::TokenKind;
+
+    fn scan_bigint(input: &str) -> crate::syntax::ast::bigint::BigInt {
+        match scan(input).unwrap() {
+            (TokenKind::BigIntLiteral(value), _) => value,
+            (other, _) => panic!("expected a BigIntLiteral, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn decimal_bigint_suffix() {
+        assert_eq!(
+            scan_bigint("9007199254740993n").to_str_radix(10),
+            "9007199254740993"
+        );
+    }
+
+    #[test]
+    fn radix_prefixed_bigint_suffix() {
+        assert_eq!(scan_bigint("0xffn").to_str_radix(10), "255");
+        assert_eq!(scan_bigint("0o17n").to_str_radix(10), "15");
+        assert_eq!(scan_bigint("0b101n").to_str_radix(10), "5");
+    }
+
+    #[test]
+    fn bigint_suffix_consumes_trailing_n() {
+        let (_, len) = scan("123n + 1").unwrap();
+        assert_eq!(len, 4);
+    }
+
+    #[test]
+    fn fractional_bigint_is_a_syntax_error() {
+        assert!(scan("1.5n").is_err());
+    }
+
+    #[test]
+    fn exponent_bigint_is_a_syntax_error() {
+        assert!(scan("1e10n").is_err());
+    }
+
+    #[test]
+    fn plain_numeric_literal_without_suffix() {
+        assert!(matches!(
+            scan("42").unwrap(),
+            (TokenKind::NumericLiteral(n), 2) if n == 42.0
+        ));
+    }
+
+    #[test]
+    fn leading_zero_decimal_bigint_is_a_syntax_error() {
+        assert!(scan("007n").is_err());
+        assert!(scan("010n").is_err());
+    }
+
+    #[test]
+    fn lone_zero_bigint_is_not_a_syntax_error() {
+        assert_eq!(scan_bigint("0n").to_str_radix(10), "0");
+    }
+
+    #[test]
+    fn large_hex_literal_without_suffix_does_not_overflow_i64() {
+        let (kind, len) = scan("0xFFFFFFFF00000000").unwrap();
+        assert_eq!(len, "0xFFFFFFFF00000000".len());
+        match kind {
+            TokenKind::NumericLiteral(n) => assert_eq!(n, 0xFFFFFFFF00000000u64 as f64),
+            other => panic!("expected a NumericLiteral, got {:?}", other),
+        }
+    }
+}
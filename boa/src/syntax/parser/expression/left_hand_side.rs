@@ -0,0 +1,48 @@
+//! Left-hand-side expression parsing.
+//!
+//! More information:
+//!  - [ECMAScript specification][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-left-hand-side-expressions
+
+use super::primary::PrimaryExpression;
+use crate::syntax::{
+    ast::node::Node,
+    parser::{AllowAwait, AllowYield, Cursor, Interner, ParseResult, TokenParser},
+};
+
+/// Parses a left-hand-side expression.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-LeftHandSideExpression
+#[derive(Debug, Clone, Copy)]
+pub(super) struct LeftHandSideExpression {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl LeftHandSideExpression {
+    /// Creates a new `LeftHandSideExpression` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl TokenParser for LeftHandSideExpression {
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<'_>, interner: &mut Interner) -> ParseResult {
+        // Call and member expressions build on top of this, but are left
+        // for a follow-up once tagged templates and optional chaining land.
+        PrimaryExpression::new(self.allow_yield, self.allow_await).parse(cursor, interner)
+    }
+}
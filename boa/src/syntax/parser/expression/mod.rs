@@ -0,0 +1,10 @@
+//! Expression parsing.
+//!
+//! More information:
+//!  - [ECMAScript specification][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-ecmascript-language-expressions
+
+pub(super) mod left_hand_side;
+pub(super) mod primary;
+pub(super) mod update;
@@ -0,0 +1,84 @@
+//! Primary expression parsing.
+//!
+//! More information:
+//!  - [ECMAScript specification][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-primary-expression
+
+use crate::syntax::{
+    ast::{node::Node, token::TokenKind},
+    parser::{AllowAwait, AllowYield, Cursor, Interner, ParseError, ParseResult, TokenParser},
+};
+
+/// Parses a primary expression.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-PrimaryExpression
+#[derive(Debug, Clone, Copy)]
+pub(super) struct PrimaryExpression {
+    #[allow(dead_code)]
+    allow_yield: AllowYield,
+    #[allow(dead_code)]
+    allow_await: AllowAwait,
+}
+
+impl PrimaryExpression {
+    /// Creates a new `PrimaryExpression` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl TokenParser for PrimaryExpression {
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<'_>, _interner: &mut Interner) -> ParseResult {
+        let tok = cursor.next().ok_or(ParseError::AbruptEnd)?;
+        match &tok.kind {
+            TokenKind::Identifier(name) => Ok(Node::Identifier(name.clone())),
+            TokenKind::NumericLiteral(num) => Ok(Node::NumericLiteral(*num)),
+            TokenKind::BigIntLiteral(num) => Ok(Node::BigIntLiteral(num.clone())),
+            TokenKind::StringLiteral(s) => Ok(Node::StringLiteral(s.clone())),
+            TokenKind::BooleanLiteral(b) => Ok(Node::BooleanLiteral(*b)),
+            TokenKind::NullLiteral => Ok(Node::NullLiteral),
+            _ => Err(ParseError::Syntax(format!(
+                "unexpected token in primary expression: {}",
+                tok.kind
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PrimaryExpression;
+    use crate::syntax::ast::{bigint::BigInt as AstBigInt, node::Node, token::Token};
+    use crate::syntax::parser::{Cursor, Interner, TokenParser};
+
+    #[test]
+    fn parses_bigint_literal_token_into_bigint_literal_node() {
+        let tokens = [Token::new(crate::syntax::ast::token::TokenKind::BigIntLiteral(
+            AstBigInt::from_str_radix("9007199254740993", 10).unwrap(),
+        ))];
+        let mut cursor = Cursor::new(&tokens);
+        let mut interner = Interner::default();
+
+        let node = PrimaryExpression::new(false, false)
+            .parse(&mut cursor, &mut interner)
+            .unwrap();
+
+        match node {
+            Node::BigIntLiteral(value) => assert_eq!(value.to_str_radix(10), "9007199254740993"),
+            other => panic!("expected a BigIntLiteral node, got {:?}", other),
+        }
+    }
+}
@@ -8,7 +8,7 @@
 use super::left_hand_side::LeftHandSideExpression;
 use crate::syntax::{
     ast::{node::Node, op::UnaryOp, punc::Punctuator, token::TokenKind},
-    parser::{AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser},
+    parser::{AllowAwait, AllowYield, Cursor, Interner, ParseError, ParseResult, TokenParser},
 };
 
 /// Parses an update expression.
@@ -35,6 +35,25 @@ impl UpdateExpression {
             allow_await: allow_await.into(),
         }
     }
+
+    /// Rejects `target` as an early error if it isn't a valid simple
+    /// assignment target, as `UpdateExpression`'s operand must be per the
+    /// `AssignmentTargetType` static semantics.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-update-expressions-static-semantics-early-errors
+    fn assert_simple_assignment_target(target: &Node, op: &str) -> Result<(), ParseError> {
+        if target.is_simple_assignment_target() {
+            Ok(())
+        } else {
+            Err(ParseError::Syntax(format!(
+                "invalid assignment target for operator `{}`",
+                op
+            )))
+        }
+    }
 }
 
 impl TokenParser for UpdateExpression {
@@ -45,19 +64,17 @@ impl TokenParser for UpdateExpression {
         match tok.kind {
             TokenKind::Punctuator(Punctuator::Inc) => {
                 cursor.next().expect("token disappeared");
-                return Ok(Node::unary_op(
-                    UnaryOp::IncrementPre,
-                    LeftHandSideExpression::new(self.allow_yield, self.allow_await)
-                        .parse(cursor, interner)?,
-                ));
+                let target = LeftHandSideExpression::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?;
+                Self::assert_simple_assignment_target(&target, "++")?;
+                return Ok(Node::unary_op(UnaryOp::IncrementPre, target));
             }
             TokenKind::Punctuator(Punctuator::Dec) => {
                 cursor.next().expect("token disappeared");
-                return Ok(Node::unary_op(
-                    UnaryOp::DecrementPre,
-                    LeftHandSideExpression::new(self.allow_yield, self.allow_await)
-                        .parse(cursor, interner)?,
-                ));
+                let target = LeftHandSideExpression::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?;
+                Self::assert_simple_assignment_target(&target, "--")?;
+                return Ok(Node::unary_op(UnaryOp::DecrementPre, target));
             }
             _ => {}
         }
@@ -68,10 +85,12 @@ impl TokenParser for UpdateExpression {
             match tok.kind {
                 TokenKind::Punctuator(Punctuator::Inc) => {
                     cursor.next().expect("token disappeared");
+                    Self::assert_simple_assignment_target(&lhs, "++")?;
                     return Ok(Node::unary_op(UnaryOp::IncrementPost, lhs));
                 }
                 TokenKind::Punctuator(Punctuator::Dec) => {
                     cursor.next().expect("token disappeared");
+                    Self::assert_simple_assignment_target(&lhs, "--")?;
                     return Ok(Node::unary_op(UnaryOp::DecrementPost, lhs));
                 }
                 _ => {}
@@ -80,4 +99,47 @@ impl TokenParser for UpdateExpression {
 
         Ok(lhs)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UpdateExpression;
+    use crate::syntax::ast::{node::Node, token::Token, token::TokenKind};
+    use crate::syntax::parser::{Cursor, Interner, ParseError, TokenParser};
+
+    #[test]
+    fn increments_a_simple_assignment_target() {
+        let tokens = [
+            Token::new(TokenKind::Punctuator(crate::syntax::ast::punc::Punctuator::Inc)),
+            Token::new(TokenKind::Identifier("foo".into())),
+        ];
+        let mut cursor = Cursor::new(&tokens);
+        let mut interner = Interner::default();
+
+        let node = UpdateExpression::new(false, false)
+            .parse(&mut cursor, &mut interner)
+            .unwrap();
+
+        assert_eq!(
+            node,
+            Node::unary_op(
+                crate::syntax::ast::op::UnaryOp::IncrementPre,
+                Node::Identifier("foo".into()),
+            )
+        );
+    }
+
+    #[test]
+    fn rejects_a_non_reference_operand_as_a_syntax_error() {
+        let tokens = [
+            Token::new(TokenKind::Punctuator(crate::syntax::ast::punc::Punctuator::Inc)),
+            Token::new(TokenKind::NumericLiteral(1.0)),
+        ];
+        let mut cursor = Cursor::new(&tokens);
+        let mut interner = Interner::default();
+
+        let result = UpdateExpression::new(false, false).parse(&mut cursor, &mut interner);
+
+        assert!(matches!(result, Err(ParseError::Syntax(_))));
+    }
 }
\ No newline at end of file
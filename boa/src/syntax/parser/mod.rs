@@ -0,0 +1,85 @@
+//! This module implements the ECMAScript parser, which turns a stream of
+//! tokens produced by the lexer into an abstract syntax tree.
+
+pub mod expression;
+
+use crate::syntax::ast::{node::Node, token::Token};
+
+/// A string interner handed to every parser. It holds no state: identifiers
+/// and string literals are stored in the tree as owned `String`s.
+#[derive(Debug, Default)]
+pub struct Interner;
+
+/// A cursor over the token stream produced by the lexer.
+#[derive(Debug)]
+pub struct Cursor<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    /// Creates a new cursor over `tokens`.
+    #[inline]
+    pub fn new(tokens: &'a [Token]) -> Self {
+        Self { tokens, pos: 0 }
+    }
+
+    /// Peeks at the token `skip` positions ahead of the cursor, without
+    /// consuming it.
+    #[inline]
+    pub fn peek(&self, skip: usize) -> Option<&'a Token> {
+        self.tokens.get(self.pos + skip)
+    }
+
+    /// Consumes and returns the next token.
+    #[inline]
+    pub fn next(&mut self) -> Option<&'a Token> {
+        let tok = self.tokens.get(self.pos)?;
+        self.pos += 1;
+        Some(tok)
+    }
+}
+
+/// Whether a parser accepts a `yield` expression in its grammar context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllowYield(bool);
+
+impl From<bool> for AllowYield {
+    #[inline]
+    fn from(allow: bool) -> Self {
+        Self(allow)
+    }
+}
+
+/// Whether a parser accepts an `await` expression in its grammar context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllowAwait(bool);
+
+impl From<bool> for AllowAwait {
+    #[inline]
+    fn from(allow: bool) -> Self {
+        Self(allow)
+    }
+}
+
+/// An error produced while parsing a token stream into an AST.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// The token stream ended before a production could be completed.
+    AbruptEnd,
+    /// A general syntax error, with a human-readable description.
+    Syntax(String),
+}
+
+/// The result of running a [`TokenParser`].
+pub type ParseResult = Result<Node, ParseError>;
+
+/// A parser for a single grammar production, consuming tokens from a
+/// [`Cursor`] and producing a [`Node`].
+pub trait TokenParser: Sized {
+    /// The node type produced by this parser.
+    type Output;
+
+    /// Parses the tokens under `cursor` into `Self::Output`.
+    fn parse(self, cursor: &mut Cursor<'_>, interner: &mut Interner) -> ParseResult;
+}